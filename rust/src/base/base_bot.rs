@@ -1,5 +1,5 @@
-use crate::lib::game::poker_moves::PokerMove;
-use crate::lib::game::poker_state::{GameState, RoundState, TerminalState};
+use crate::game::poker_moves::PokerMove;
+use crate::game::poker_state::{GameState, RoundState, TerminalState};
 
 pub trait BaseBot {
     fn handle_new_round(&mut self, game_state: &GameState, round_state: &RoundState, active: usize);
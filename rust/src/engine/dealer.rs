@@ -0,0 +1,218 @@
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::base::base_bot::BaseBot;
+use crate::game::poker_moves::PokerMove;
+use crate::game::poker_state::{
+    GameState, RoundState, TerminalState, BIG_BLIND, NUM_ROUNDS, SMALL_BLIND, STARTING_STACK,
+};
+
+const RANKS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+const SUITS: [char; 4] = ['c', 'd', 'h', 's'];
+
+/// The MIT bounty bonus: a winner who hits their bounty gets their delta
+/// boosted 1.5x plus a flat 10-chip add-on, capped at the pot.
+const BOUNTY_MULTIPLIER: f64 = 1.5;
+const BOUNTY_BONUS: i32 = 10;
+
+fn fresh_deck() -> Vec<String> {
+    let mut deck: Vec<String> = SUITS
+        .iter()
+        .flat_map(|&s| RANKS.iter().map(move |&r| format!("{}{}", r, s)))
+        .collect();
+    deck.shuffle(&mut thread_rng());
+    deck
+}
+
+fn random_bounty_rank() -> String {
+    RANKS
+        .choose(&mut thread_rng())
+        .expect("RANKS is non-empty")
+        .to_string()
+}
+
+/// Clamps or replaces a bot's chosen action so the dealer never applies an
+/// illegal move to `round`.
+fn sanitize_action(round: &RoundState, action: PokerMove) -> PokerMove {
+    let legal = round.legal_actions();
+
+    match action {
+        PokerMove::Raise(amount) if legal.contains(&PokerMove::Raise(0)) => {
+            let (min_raise, max_raise) = round.raise_bounds();
+            PokerMove::Raise(amount.clamp(min_raise, max_raise))
+        }
+        other if legal.contains(&other) => other,
+        _ => round.safe_default_action(),
+    }
+}
+
+/// Reveals however much of `board` is public on `round`'s current street.
+fn reveal_board(mut round: RoundState, board: &[String]) -> RoundState {
+    let revealed = match round.street {
+        0 => 0,
+        3 => 3,
+        4 => 4,
+        5 => 5,
+        _ => round.deck.len(),
+    };
+    round.deck = board[..revealed].to_vec();
+    round
+}
+
+/// Boosts `deltas[winner]` by the MIT bounty bonus and debits the loser by
+/// the same amount, so the pair still sums to zero and a bounty hit can't
+/// manufacture chips out of thin air across a benchmarked match.
+fn apply_bounty_bonus(deltas: &mut [i32; 2], winner: usize, pot: i32) {
+    let boosted = (deltas[winner] as f64 * BOUNTY_MULTIPLIER).round() as i32 + BOUNTY_BONUS;
+    deltas[winner] = boosted.min(pot);
+    deltas[1 - winner] = -deltas[winner];
+}
+
+/// Settles a hand that reached showdown, which `RoundState::showdown`
+/// leaves as a placeholder with zeroed deltas. Splits the pot by hand
+/// strength (or evenly on a chop) and layers on the bounty bonus.
+fn settle_showdown(round: &RoundState) -> TerminalState {
+    let pot = 2 * STARTING_STACK - round.stacks[0] - round.stacks[1];
+    let contribution = [STARTING_STACK - round.stacks[0], STARTING_STACK - round.stacks[1]];
+    let bounty_hits = round.get_bounty_hits();
+
+    let winner = round.showdown_winner();
+    let mut deltas = match winner {
+        Some(winner) => {
+            let loser = 1 - winner;
+            let mut deltas = [0i32; 2];
+            deltas[winner] = contribution[loser];
+            deltas[loser] = -contribution[loser];
+            deltas
+        }
+        None => {
+            let half = pot / 2;
+            [half - contribution[0], pot - half - contribution[1]]
+        }
+    };
+
+    if let Some(winner) = winner {
+        if bounty_hits[winner] {
+            apply_bounty_bonus(&mut deltas, winner, pot);
+        }
+    }
+
+    TerminalState {
+        deltas,
+        bounty_hits: Some(bounty_hits),
+        previous_state: Box::new(round.clone()),
+    }
+}
+
+/// Layers the bounty bonus onto a fold terminal, whose deltas otherwise
+/// never see it (only `settle_showdown` used to apply it).
+fn apply_fold_bounty(mut terminal: TerminalState) -> TerminalState {
+    let previous = &terminal.previous_state;
+    let pot = 2 * STARTING_STACK - previous.stacks[0] - previous.stacks[1];
+    let folder = (previous.button % 2) as usize;
+    let winner = 1 - folder;
+
+    if let Some(bounty_hits) = terminal.bounty_hits {
+        if bounty_hits[winner] {
+            apply_bounty_bonus(&mut terminal.deltas, winner, pot);
+        }
+    }
+
+    terminal
+}
+
+/// Hides `round`'s non-`seat` hole cards, mirroring what `EngineClient`
+/// only ever learns about an opponent's hand over the wire.
+fn redacted_for(round: &RoundState, seat: usize) -> RoundState {
+    let mut view = round.clone();
+    view.hands[1 - seat] = Vec::new();
+    view
+}
+
+/// Plays a single hand between whichever bot occupies each button-relative
+/// seat, returning the settled `TerminalState`.
+fn play_round<A: BaseBot, B: BaseBot>(
+    bot_a: &mut A,
+    bot_b: &mut B,
+    a_seat: usize,
+    game_state_a: &GameState,
+    game_state_b: &GameState,
+) -> TerminalState {
+    let b_seat = 1 - a_seat;
+    let deck = fresh_deck();
+    let hands = [deck[0..2].to_vec(), deck[2..4].to_vec()];
+    let board = deck[4..9].to_vec();
+    let bounties = [random_bounty_rank(), random_bounty_rank()];
+
+    let mut round = RoundState {
+        button: 0,
+        street: 0,
+        pips: [SMALL_BLIND, BIG_BLIND],
+        stacks: [STARTING_STACK - SMALL_BLIND, STARTING_STACK - BIG_BLIND],
+        hands,
+        bounties,
+        deck: Vec::new(),
+        previous_state: None,
+    };
+
+    bot_a.handle_new_round(game_state_a, &redacted_for(&round, a_seat), a_seat);
+    bot_b.handle_new_round(game_state_b, &redacted_for(&round, b_seat), b_seat);
+
+    let terminal = loop {
+        let active_seat = (round.button % 2) as usize;
+        let view = redacted_for(&round, active_seat);
+
+        let action = if active_seat == a_seat {
+            bot_a.get_action(game_state_a, &view, active_seat)
+        } else {
+            bot_b.get_action(game_state_b, &view, active_seat)
+        };
+        let action = sanitize_action(&round, action);
+
+        match round.proceed(action) {
+            Ok(next) => round = reveal_board(next, &board),
+            Err(terminal) => {
+                break if terminal.bounty_hits.is_none() {
+                    settle_showdown(&terminal.previous_state)
+                } else {
+                    apply_fold_bounty(terminal)
+                };
+            }
+        }
+    };
+
+    bot_a.handle_round_over(game_state_a, &terminal, a_seat);
+    bot_b.handle_round_over(game_state_b, &terminal, b_seat);
+
+    terminal
+}
+
+/// Runs a full `NUM_ROUNDS`-hand match between two bots entirely in-process,
+/// rotating the button each round, and returns their final bankrolls.
+pub fn run_match<A: BaseBot, B: BaseBot>(mut bot_a: A, mut bot_b: B) -> [i32; 2] {
+    let mut bankrolls = [0i32, 0i32];
+    let mut game_state_a = GameState {
+        bankroll: 0,
+        game_clock: 0.0,
+        round_num: 1,
+    };
+    let mut game_state_b = game_state_a.clone();
+
+    for round_num in 0..NUM_ROUNDS {
+        let a_seat = (round_num % 2) as usize;
+
+        let terminal = play_round(&mut bot_a, &mut bot_b, a_seat, &game_state_a, &game_state_b);
+
+        bankrolls[0] += terminal.deltas[a_seat];
+        bankrolls[1] += terminal.deltas[1 - a_seat];
+
+        game_state_a.bankroll = bankrolls[0];
+        game_state_b.bankroll = bankrolls[1];
+        game_state_a.round_num += 1;
+        game_state_b.round_num += 1;
+    }
+
+    bankrolls
+}
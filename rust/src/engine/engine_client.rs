@@ -0,0 +1,319 @@
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use clap::Parser;
+
+use crate::base::base_bot::BaseBot;
+use crate::engine::parser::{parse_clause, ParseError, ServerMessage};
+use crate::engine::recorder::MatchRecorder;
+use crate::game::poker_moves::PokerMove;
+use crate::game::poker_state::{GameState, RoundState, TerminalState};
+use crate::game::poker_state::{STARTING_STACK, BIG_BLIND, SMALL_BLIND};
+
+/// An error encountered while driving the engine connection: either the
+/// socket failed, or the server sent a clause `parser::parse_clause`
+/// couldn't make sense of.
+#[derive(Debug)]
+pub enum RunError {
+    Io(std::io::Error),
+    Protocol(ParseError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Io(err) => write!(f, "socket error: {}", err),
+            RunError::Protocol(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<std::io::Error> for RunError {
+    fn from(err: std::io::Error) -> Self {
+        RunError::Io(err)
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(err: ParseError) -> Self {
+        RunError::Protocol(err)
+    }
+}
+
+pub struct EngineClient<T: BaseBot> {
+    pokerbot: Arc<Mutex<T>>,
+    stream: TcpStream,
+    recorder: Option<MatchRecorder>,
+    /// Set once a `get_action` call has blown through its deadline. The
+    /// worker thread that missed its deadline may still be holding
+    /// `pokerbot`'s lock (forever, if the bot is truly hung), so once this
+    /// is set we never attempt to lock `pokerbot` again for the rest of
+    /// the match.
+    unresponsive: Arc<AtomicBool>,
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    /// Host to connect to
+    #[arg(long, default_value = "localhost")]
+    pub host: String,
+
+    /// Port to connect to
+    pub port: u16,
+
+    /// Append a newline-delimited JSON replay log to this path
+    #[arg(long)]
+    pub log: Option<PathBuf>,
+}
+
+impl<T: BaseBot + Send + 'static> EngineClient<T> {
+    pub fn new(pokerbot: T, stream: TcpStream, log_path: Option<&std::path::Path>) -> Self {
+        let recorder = log_path.and_then(|path| MatchRecorder::create(path).ok());
+        EngineClient {
+            pokerbot: Arc::new(Mutex::new(pokerbot)),
+            stream,
+            recorder,
+            unresponsive: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn send(&mut self, action: PokerMove) {
+        let code = match action {
+            PokerMove::Fold => "F".to_string(),
+            PokerMove::Call => "C".to_string(),
+            PokerMove::Check => "K".to_string(),
+            PokerMove::Raise(amount) => format!("R{}", amount),
+        };
+
+        writeln!(self.stream, "{}", code).expect("Failed to write to socket");
+    }
+
+    pub fn run(&mut self) -> Result<(), RunError> {
+        let mut game_state = GameState {
+            bankroll: 0,
+            game_clock: 0.0,
+            round_num: 1,
+        };
+
+        let mut round_state = None;
+        let mut active = 0;
+        let mut round_flag = true;
+        let mut last_deltas = [0i32, 0i32];
+
+        let reader = BufReader::new(self.stream.try_clone()?);
+
+        for line in reader.lines() {
+            let line = line?;
+            let packet: Vec<&str> = line.trim().split(' ').filter(|clause| !clause.is_empty()).collect();
+
+            for clause in packet {
+                // An unknown tag or malformed payload shouldn't tear down
+                // an otherwise-healthy match (the baseline just ignored
+                // these); log it and move on to the next clause instead.
+                let message = match parse_clause(clause) {
+                    Ok(message) => message,
+                    Err(err) => {
+                        eprintln!("ignoring unparseable clause {:?}: {}", clause, err);
+                        continue;
+                    }
+                };
+
+                match message {
+                    ServerMessage::Time(time) => {
+                        game_state.game_clock = time;
+                    },
+                    ServerMessage::PlayerPos(pos) => {
+                        active = pos;
+                    },
+                    ServerMessage::Hand(cards) => {
+                        let mut hands = [Vec::new(), Vec::new()];
+                        hands[active] = cards.iter().map(|card| card.to_string()).collect();
+                        let pips = [SMALL_BLIND, BIG_BLIND];
+                        let stacks = [STARTING_STACK - SMALL_BLIND, STARTING_STACK - BIG_BLIND];
+
+                        round_state = Some(RoundState {
+                            button: 0,
+                            street: 0,
+                            pips,
+                            stacks,
+                            hands,
+                            bounties: ["-1".to_string(), "-1".to_string()],
+                            deck: Vec::new(),
+                            previous_state: None,
+                        });
+                    },
+                    ServerMessage::Bounty(rank) => {
+                        if let Some(rs) = &mut round_state {
+                            rs.bounties[active] = rank;
+
+                            if round_flag {
+                                if !self.unresponsive.load(Ordering::Acquire) {
+                                    self.pokerbot.lock().unwrap().handle_new_round(&game_state, rs, active);
+                                }
+                                round_flag = false;
+                            }
+                        }
+                    },
+                    ServerMessage::Action(action) => {
+                        if let Some(rs) = &round_state {
+                            if let Ok(new_rs) = rs.proceed(action) {
+                                round_state = Some(new_rs);
+                            }
+                        }
+                    },
+                    ServerMessage::Board(cards) => {
+                        if let Some(rs) = &mut round_state {
+                            rs.deck = cards.iter().map(|card| card.to_string()).collect();
+                        }
+                    },
+                    ServerMessage::Opponent(cards) => {
+                        if let Some(rs) = &mut round_state {
+                            if let Some(prev_state) = &rs.previous_state {
+                                let mut new_rs = (**prev_state).clone();
+                                new_rs.hands[1 - active] = cards.iter().map(|card| card.to_string()).collect();
+
+                                // dont update round_state here because were transitioning to a terminal state
+                                let _ = new_rs;
+                            }
+                        }
+                    },
+                    ServerMessage::Delta(delta) => {
+                        if round_state.is_some() {
+                            let mut deltas = [-delta, -delta];
+                            deltas[active] = delta;
+                            last_deltas = deltas;
+
+                            game_state.bankroll += delta;
+                        }
+                    },
+                    ServerMessage::BountyResult(hits) => {
+                        if let Some(rs) = &round_state {
+                            let [hero_hit_bounty, opponent_hit_bounty] = hits;
+
+                            let bounty_hits = if active == 1 {
+                                [opponent_hit_bounty, hero_hit_bounty]
+                            } else {
+                                [hero_hit_bounty, opponent_hit_bounty]
+                            };
+
+                            let terminal_state = TerminalState {
+                                deltas: last_deltas,
+                                bounty_hits: Some(bounty_hits),
+                                previous_state: Box::new(rs.clone()),
+                            };
+
+                            if !self.unresponsive.load(Ordering::Acquire) {
+                                self.pokerbot.lock().unwrap().handle_round_over(&game_state, &terminal_state, active);
+                            }
+
+                            if let Some(recorder) = &mut self.recorder {
+                                let _ = recorder.record_round_end(game_state.round_num, &terminal_state);
+                            }
+
+                            game_state.round_num += 1;
+                            round_flag = true;
+                        }
+                    },
+                    ServerMessage::NewHand => {},
+                    ServerMessage::Quit => return Ok(()),
+                }
+            }
+
+            if round_flag {
+                self.send(PokerMove::Check);
+            } else if let Some(rs) = &round_state {
+                if active != (rs.button % 2) as usize {
+                    return Err(RunError::Protocol(ParseError(format!(
+                        "server expects seat {} to act but local state has seat {} up",
+                        active,
+                        rs.button % 2
+                    ))));
+                }
+
+                let action = self.get_action_with_deadline(&game_state, rs, active);
+
+                if let Some(recorder) = &mut self.recorder {
+                    let _ = recorder.record_decision(game_state.round_num, active, rs, action);
+                }
+
+                self.send(action);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs `get_action` on a worker thread under `game_state`'s decision
+    /// budget. A bot that blows through its budget forfeits the move: we
+    /// fall back to a safe default and keep going rather than stall the
+    /// clock (or the socket) waiting on it.
+    ///
+    /// Rust has no safe way to kill a running thread, so a bot that never
+    /// returns leaves its worker holding `pokerbot`'s lock forever. Once
+    /// that happens we mark the bot `unresponsive` and stop locking it for
+    /// the rest of the match (falling back to a safe default on every
+    /// later decision too) instead of letting the next lock attempt block
+    /// indefinitely on a hung thread.
+    fn get_action_with_deadline(
+        &self,
+        game_state: &GameState,
+        round_state: &RoundState,
+        active: usize,
+    ) -> PokerMove {
+        if self.unresponsive.load(Ordering::Acquire) {
+            return round_state.safe_default_action();
+        }
+
+        let budget = game_state.decision_budget();
+        let pokerbot = Arc::clone(&self.pokerbot);
+        let unresponsive = Arc::clone(&self.unresponsive);
+        let game_state_for_thread = game_state.clone();
+        let round_state_for_thread = round_state.clone();
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let action = pokerbot
+                .lock()
+                .unwrap()
+                .get_action(&game_state_for_thread, &round_state_for_thread, active);
+            let _ = tx.send(action);
+        });
+
+        match rx.recv_timeout(budget) {
+            Ok(action) => action,
+            Err(_) => {
+                unresponsive.store(true, Ordering::Release);
+                eprintln!(
+                    "get_action exceeded its {:?} budget on round {}; bot is now treated as unresponsive for the rest of the match",
+                    budget, game_state.round_num
+                );
+                round_state.safe_default_action()
+            }
+        }
+    }
+}
+
+pub fn parse_args() -> Args {
+    Args::parse()
+}
+
+pub fn run_bot<T: BaseBot + Send + 'static>(pokerbot: T, args: Args) {
+    match TcpStream::connect(format!("{}:{}", args.host, args.port)) {
+        Ok(stream) => {
+            let mut client = EngineClient::new(pokerbot, stream, args.log.as_deref());
+            if let Err(err) = client.run() {
+                eprintln!("engine client error: {}", err);
+            }
+        },
+        Err(_) => {
+            eprintln!("Could not connect to {}:{}", args.host, args.port);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod dealer;
+pub mod engine_client;
+pub mod parser;
+pub mod recorder;
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::game::cards::Card;
+use crate::game::poker_moves::PokerMove;
+
+/// A single clause from the engine's wire protocol, typed instead of
+/// matched on a raw leading character.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerMessage {
+    Time(f64),
+    PlayerPos(usize),
+    Hand(Vec<Card>),
+    Bounty(String),
+    Action(PokerMove),
+    Board(Vec<Card>),
+    Opponent(Vec<Card>),
+    Delta(i32),
+    BountyResult([bool; 2]),
+    NewHand,
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(pub String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse server clause: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_cards(rest: &str) -> Result<Vec<Card>, ParseError> {
+    rest.split(',')
+        .filter(|wire| !wire.is_empty())
+        .map(|wire| Card::parse(wire).ok_or_else(|| ParseError(format!("bad card {:?}", wire))))
+        .collect()
+}
+
+/// Parses one space-separated clause of an engine packet, e.g. `"R40"` or
+/// `"B2h,Tc,9s"`, into a typed [`ServerMessage`]. Returns `Err` on truncated
+/// or malformed input instead of panicking.
+pub fn parse_clause(clause: &str) -> Result<ServerMessage, ParseError> {
+    let mut chars = clause.chars();
+    let tag = chars
+        .next()
+        .ok_or_else(|| ParseError("empty clause".to_string()))?;
+    let rest = chars.as_str();
+
+    match tag {
+        'T' => rest
+            .parse::<f64>()
+            .map(ServerMessage::Time)
+            .map_err(|_| ParseError(format!("bad time {:?}", rest))),
+        'P' => rest
+            .parse::<usize>()
+            .map(ServerMessage::PlayerPos)
+            .map_err(|_| ParseError(format!("bad position {:?}", rest))),
+        'H' => parse_cards(rest).map(ServerMessage::Hand),
+        'G' => Ok(ServerMessage::Bounty(rest.to_string())),
+        'F' => Ok(ServerMessage::Action(PokerMove::Fold)),
+        'C' => Ok(ServerMessage::Action(PokerMove::Call)),
+        'K' => Ok(ServerMessage::Action(PokerMove::Check)),
+        'R' => rest
+            .parse::<i32>()
+            .map(|amount| ServerMessage::Action(PokerMove::Raise(amount)))
+            .map_err(|_| ParseError(format!("bad raise amount {:?}", rest))),
+        'B' => parse_cards(rest).map(ServerMessage::Board),
+        'O' => parse_cards(rest).map(ServerMessage::Opponent),
+        'D' => rest
+            .parse::<i32>()
+            .map(ServerMessage::Delta)
+            .map_err(|_| ParseError(format!("bad delta {:?}", rest))),
+        'Y' => {
+            let hits: Vec<char> = rest.chars().collect();
+            if hits.len() < 2 {
+                return Err(ParseError(format!("bad bounty result {:?}", rest)));
+            }
+            Ok(ServerMessage::BountyResult([hits[0] == '1', hits[1] == '1']))
+        }
+        'N' => Ok(ServerMessage::NewHand),
+        'Q' => Ok(ServerMessage::Quit),
+        _ => Err(ParseError(format!("unknown clause tag {:?}", tag))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_time() {
+        assert_eq!(parse_clause("T5.5"), Ok(ServerMessage::Time(5.5)));
+    }
+
+    #[test]
+    fn parses_player_pos() {
+        assert_eq!(parse_clause("P1"), Ok(ServerMessage::PlayerPos(1)));
+    }
+
+    #[test]
+    fn parses_hand() {
+        assert_eq!(
+            parse_clause("HAh,Td"),
+            Ok(ServerMessage::Hand(vec![
+                Card::parse("Ah").unwrap(),
+                Card::parse("Td").unwrap(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parses_fold_call_check() {
+        assert_eq!(parse_clause("F"), Ok(ServerMessage::Action(PokerMove::Fold)));
+        assert_eq!(parse_clause("C"), Ok(ServerMessage::Action(PokerMove::Call)));
+        assert_eq!(parse_clause("K"), Ok(ServerMessage::Action(PokerMove::Check)));
+    }
+
+    #[test]
+    fn parses_raise() {
+        assert_eq!(
+            parse_clause("R40"),
+            Ok(ServerMessage::Action(PokerMove::Raise(40)))
+        );
+    }
+
+    #[test]
+    fn parses_bounty_result() {
+        assert_eq!(parse_clause("Y10"), Ok(ServerMessage::BountyResult([true, false])));
+    }
+
+    #[test]
+    fn parses_quit() {
+        assert_eq!(parse_clause("Q"), Ok(ServerMessage::Quit));
+    }
+
+    #[test]
+    fn rejects_empty_clause() {
+        assert!(parse_clause("").is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_time() {
+        assert!(parse_clause("T").is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_time() {
+        assert!(parse_clause("Tnotanumber").is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_bounty_result() {
+        assert!(parse_clause("Y1").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_card() {
+        assert!(parse_clause("HZz").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_tag() {
+        assert!(parse_clause("Z").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::base_bot::BaseBot;
+use crate::game::poker_moves::PokerMove;
+use crate::game::poker_state::{GameState, RoundState, TerminalState};
+
+/// One bot decision point, logged with enough state to replay it later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub round_num: i32,
+    pub active: usize,
+    pub legal_actions: Vec<PokerMove>,
+    pub street: i32,
+    pub pips: [i32; 2],
+    pub stacks: [i32; 2],
+    pub hole_cards: Vec<String>,
+    pub board_cards: Vec<String>,
+    pub action: PokerMove,
+}
+
+/// The settlement of a round, logged alongside its decisions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundEndRecord {
+    pub round_num: i32,
+    pub deltas: [i32; 2],
+    pub bounty_hits: Option<[bool; 2]>,
+}
+
+/// Appends a newline-delimited JSON record per decision point and per round
+/// settlement, for offline analysis or replay.
+pub struct MatchRecorder {
+    writer: BufWriter<File>,
+}
+
+impl MatchRecorder {
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(MatchRecorder {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn record_decision(
+        &mut self,
+        round_num: i32,
+        active: usize,
+        round_state: &RoundState,
+        action: PokerMove,
+    ) -> std::io::Result<()> {
+        let record = DecisionRecord {
+            round_num,
+            active,
+            legal_actions: round_state.legal_actions().into_iter().collect(),
+            street: round_state.street,
+            pips: round_state.pips,
+            stacks: round_state.stacks,
+            hole_cards: round_state.hands[active].clone(),
+            board_cards: round_state.deck.clone(),
+            action,
+        };
+
+        self.write_line(&record)
+    }
+
+    pub fn record_round_end(
+        &mut self,
+        round_num: i32,
+        terminal_state: &TerminalState,
+    ) -> std::io::Result<()> {
+        let record = RoundEndRecord {
+            round_num,
+            deltas: terminal_state.deltas,
+            bounty_hits: terminal_state.bounty_hits,
+        };
+
+        self.write_line(&record)
+    }
+
+    fn write_line<T: Serialize>(&mut self, record: &T) -> std::io::Result<()> {
+        let line = serde_json::to_string(record).expect("record is always serializable");
+        writeln!(self.writer, "{}", line)?;
+        self.writer.flush()
+    }
+}
+
+/// A logged decision where the bot, replayed against the same state, chose a
+/// different action than it did in the original match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayMismatch {
+    pub round_num: i32,
+    pub expected: PokerMove,
+    pub actual: PokerMove,
+}
+
+/// Replays every decision logged at `path` through `bot`, feeding it the
+/// recorded state and diffing its action against what was actually played.
+/// Lets strategy changes be regression-tested offline against historical
+/// hands instead of against the live engine.
+pub fn replay_match<T: BaseBot>(path: &Path, bot: &mut T) -> std::io::Result<Vec<ReplayMismatch>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let mut mismatches = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Ok(record) = serde_json::from_str::<DecisionRecord>(&line) else {
+            continue;
+        };
+
+        let game_state = GameState {
+            bankroll: 0,
+            game_clock: 0.0,
+            round_num: record.round_num,
+        };
+
+        let mut hands = [Vec::new(), Vec::new()];
+        hands[record.active] = record.hole_cards.clone();
+        let round_state = RoundState {
+            // `legal_actions`/`raise_bounds` derive the actor from
+            // `button % 2`, so this has to match the seat the decision was
+            // actually logged for.
+            button: record.active as i32,
+            street: record.street,
+            pips: record.pips,
+            stacks: record.stacks,
+            hands,
+            bounties: ["-1".to_string(), "-1".to_string()],
+            deck: record.board_cards.clone(),
+            previous_state: None,
+        };
+
+        let replayed = bot.get_action(&game_state, &round_state, record.active);
+        if replayed != record.action {
+            mismatches.push(ReplayMismatch {
+                round_num: record.round_num,
+                expected: record.action,
+                actual: replayed,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
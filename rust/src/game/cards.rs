@@ -0,0 +1,269 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    fn from_char(c: char) -> Option<Rank> {
+        use Rank::*;
+        Some(match c {
+            '2' => Two,
+            '3' => Three,
+            '4' => Four,
+            '5' => Five,
+            '6' => Six,
+            '7' => Seven,
+            '8' => Eight,
+            '9' => Nine,
+            'T' => Ten,
+            'J' => Jack,
+            'Q' => Queen,
+            'K' => King,
+            'A' => Ace,
+            _ => return None,
+        })
+    }
+
+    fn to_char(self) -> char {
+        use Rank::*;
+        match self {
+            Two => '2',
+            Three => '3',
+            Four => '4',
+            Five => '5',
+            Six => '6',
+            Seven => '7',
+            Eight => '8',
+            Nine => '9',
+            Ten => 'T',
+            Jack => 'J',
+            Queen => 'Q',
+            King => 'K',
+            Ace => 'A',
+        }
+    }
+
+    /// Numeric value used for tiebreaking, with Ace high (14).
+    fn value(self) -> u8 {
+        self as u8 + 2
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    fn from_char(c: char) -> Option<Suit> {
+        use Suit::*;
+        Some(match c {
+            'c' => Clubs,
+            'd' => Diamonds,
+            'h' => Hearts,
+            's' => Spades,
+            _ => return None,
+        })
+    }
+
+    fn to_char(self) -> char {
+        use Suit::*;
+        match self {
+            Clubs => 'c',
+            Diamonds => 'd',
+            Hearts => 'h',
+            Spades => 's',
+        }
+    }
+}
+
+/// A single playing card, parsed from the two-character wire format used by
+/// the engine (e.g. "Ah", "Td", "2s").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl Card {
+    pub fn parse(wire: &str) -> Option<Card> {
+        let mut chars = wire.chars();
+        let rank = Rank::from_char(chars.next()?)?;
+        let suit = Suit::from_char(chars.next()?)?;
+        if chars.next().is_some() {
+            return None;
+        }
+        Some(Card { rank, suit })
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank.to_char(), self.suit.to_char())
+    }
+}
+
+/// The category a 5-card hand falls into, ordered so that derived `Ord`
+/// matches poker hand strength (weakest first).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandCategory {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+/// A totally-ordered showdown strength: the hand's category plus its
+/// tiebreak ranks in descending order of significance. Comparing two
+/// `HandValue`s with `cmp` is all a caller needs to resolve a showdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandValue {
+    category: HandCategory,
+    tiebreaks: [u8; 5],
+}
+
+impl HandValue {
+    pub fn category(&self) -> HandCategory {
+        self.category
+    }
+}
+
+/// Scores the best 5-card hand obtainable from `cards` (2 hole cards plus up
+/// to 5 board cards, so between 5 and 7 cards total).
+pub fn evaluate_best(cards: &[Card]) -> HandValue {
+    assert!(cards.len() >= 5, "need at least 5 cards to evaluate a hand");
+
+    combinations(cards, 5)
+        .map(|five| score_five(&five))
+        .max()
+        .expect("at least one 5-card combination exists")
+}
+
+fn combinations(cards: &[Card], k: usize) -> impl Iterator<Item = Vec<Card>> + '_ {
+    let n = cards.len();
+    let mut indices: Vec<usize> = (0..k).collect();
+    let mut done = n < k;
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let current: Vec<Card> = indices.iter().map(|&i| cards[i]).collect();
+
+        // Advance to the next combination in lexicographic order of indices.
+        let mut i = k as isize - 1;
+        while i >= 0 && indices[i as usize] == i as usize + n - k {
+            i -= 1;
+        }
+        if i < 0 {
+            done = true;
+        } else {
+            let i = i as usize;
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+        }
+
+        Some(current)
+    })
+}
+
+fn score_five(cards: &[Card]) -> HandValue {
+    assert_eq!(cards.len(), 5);
+
+    let mut values: Vec<u8> = cards.iter().map(|c| c.rank.value()).collect();
+    values.sort_unstable_by(|a, b| b.cmp(a));
+
+    let is_flush = cards.iter().all(|c| c.suit == cards[0].suit);
+    let straight_high = straight_high_card(&values);
+
+    let mut counts: Vec<(u8, u8)> = Vec::new();
+    for &v in &values {
+        match counts.iter_mut().find(|(rank, _)| *rank == v) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((v, 1)),
+        }
+    }
+    counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+    let ranked: Vec<u8> = counts.iter().map(|(rank, _)| *rank).collect();
+    let shape: Vec<u8> = counts.iter().map(|(_, count)| *count).collect();
+
+    let (category, tiebreaks): (HandCategory, [u8; 5]) = if let (true, Some(high)) = (is_flush, straight_high) {
+        (HandCategory::StraightFlush, pad(&[high]))
+    } else if shape == [4, 1] {
+        (HandCategory::FourOfAKind, pad(&ranked))
+    } else if shape == [3, 2] {
+        (HandCategory::FullHouse, pad(&ranked))
+    } else if is_flush {
+        (HandCategory::Flush, pad(&values))
+    } else if let Some(high) = straight_high {
+        (HandCategory::Straight, pad(&[high]))
+    } else if shape == [3, 1, 1] {
+        (HandCategory::ThreeOfAKind, pad(&ranked))
+    } else if shape == [2, 2, 1] {
+        (HandCategory::TwoPair, pad(&ranked))
+    } else if shape == [2, 1, 1, 1] {
+        (HandCategory::Pair, pad(&ranked))
+    } else {
+        (HandCategory::HighCard, pad(&values))
+    };
+
+    HandValue {
+        category,
+        tiebreaks,
+    }
+}
+
+/// Returns the high card of a straight among `values` (five descending,
+/// deduplication not required since duplicate ranks can't form a straight),
+/// treating A-2-3-4-5 (the wheel) as a 5-high straight.
+fn straight_high_card(values: &[u8]) -> Option<u8> {
+    let mut unique: Vec<u8> = values.to_vec();
+    unique.dedup();
+    if unique.len() != 5 {
+        return None;
+    }
+
+    if unique.windows(2).all(|w| w[0] - w[1] == 1) {
+        return Some(unique[0]);
+    }
+
+    if unique == [14, 5, 4, 3, 2] {
+        return Some(5);
+    }
+
+    None
+}
+
+fn pad(values: &[u8]) -> [u8; 5] {
+    let mut out = [0u8; 5];
+    for (slot, &v) in out.iter_mut().zip(values.iter()) {
+        *slot = v;
+    }
+    out
+}
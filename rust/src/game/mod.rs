@@ -0,0 +1,3 @@
+pub mod cards;
+pub mod poker_moves;
+pub mod poker_state;
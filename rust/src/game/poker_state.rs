@@ -1,26 +1,53 @@
+use super::cards::{evaluate_best, Card, HandValue};
 use super::poker_moves::PokerMove;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 pub const NUM_ROUNDS: i32 = 1000;
 pub const STARTING_STACK: i32 = 400;
 pub const BIG_BLIND: i32 = 2;
 pub const SMALL_BLIND: i32 = 1;
 
-#[derive(Debug, Clone)]
+/// Rough number of decisions left to budget `game_clock` across, used to
+/// size a single action's time slice. Tuned for heads-up NLHE, where a
+/// round rarely goes past a handful of bets per street.
+const ESTIMATED_REMAINING_DECISIONS: f64 = 6.0;
+
+/// Floor on a single decision's budget so a near-exhausted clock still
+/// leaves a strategy enough time to return *a* legal action.
+const MIN_DECISION_BUDGET: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub bankroll: i32,
     pub game_clock: f64,
     pub round_num: i32,
 }
 
-#[derive(Debug, Clone)]
+impl GameState {
+    /// The time a single `get_action` call should be given, derived from
+    /// the remaining `game_clock`. Search-based strategies can treat this
+    /// as a target for iterative deepening.
+    pub fn decision_budget(&self) -> Duration {
+        let seconds = self.game_clock / ESTIMATED_REMAINING_DECISIONS;
+        Duration::from_secs_f64(seconds.max(0.0)).max(MIN_DECISION_BUDGET)
+    }
+
+    /// The instant by which the current decision should be made.
+    pub fn decision_deadline(&self) -> Instant {
+        Instant::now() + self.decision_budget()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TerminalState {
     pub deltas: [i32; 2],
     pub bounty_hits: Option<[bool; 2]>,
     pub previous_state: Box<RoundState>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoundState {
     pub button: i32,
     pub street: i32,
@@ -58,6 +85,50 @@ impl RoundState {
         }
     }
 
+    /// Scores `active`'s best 5-card hand out of their hole cards plus
+    /// whatever board cards have been dealt so far. Returns `None` until
+    /// there are at least 5 cards to evaluate (2 hole + 3 board).
+    pub fn evaluate(&self, active: usize) -> Option<HandValue> {
+        let mut cards: Vec<Card> = self.hands[active]
+            .iter()
+            .filter_map(|wire| Card::parse(wire))
+            .collect();
+        cards.extend(self.deck.iter().filter_map(|wire| Card::parse(wire)));
+
+        if cards.len() < 5 {
+            return None;
+        }
+
+        Some(evaluate_best(&cards))
+    }
+
+    /// Compares both players' best hands at showdown. `None` means a chop
+    /// (exact tie) or that the board isn't complete enough to score yet.
+    pub fn showdown_winner(&self) -> Option<usize> {
+        let hand0 = self.evaluate(0)?;
+        let hand1 = self.evaluate(1)?;
+
+        match hand0.cmp(&hand1) {
+            std::cmp::Ordering::Greater => Some(0),
+            std::cmp::Ordering::Less => Some(1),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// A conservative action to fall back on when a bot's chosen move can't
+    /// be trusted as-is (illegal, or simply late): check if possible,
+    /// otherwise call, otherwise fold.
+    pub fn safe_default_action(&self) -> PokerMove {
+        let legal = self.legal_actions();
+        if legal.contains(&PokerMove::Check) {
+            PokerMove::Check
+        } else if legal.contains(&PokerMove::Call) {
+            PokerMove::Call
+        } else {
+            PokerMove::Fold
+        }
+    }
+
     pub fn legal_actions(&self) -> HashSet<PokerMove> {
         let active = (self.button % 2) as usize;
         let continue_cost = self.pips[1 - active] - self.pips[active];
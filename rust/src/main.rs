@@ -1,12 +1,10 @@
 use rand::Rng;
 use std::collections::HashSet;
 
-mod lib;
-
-use lib::base::base_bot::BaseBot;
-use lib::engine::engine_client::{parse_args, run_bot};
-use lib::game::poker_moves::PokerMove;
-use lib::game::poker_state::{GameState, RoundState, TerminalState};
+use pab_pokerbots::base::base_bot::BaseBot;
+use pab_pokerbots::engine::engine_client::{parse_args, run_bot};
+use pab_pokerbots::game::poker_moves::PokerMove;
+use pab_pokerbots::game::poker_state::{GameState, RoundState, TerminalState};
 
 struct PokerStrategy;
 
@@ -29,20 +27,20 @@ impl BaseBot for PokerStrategy {
     fn get_action(&mut self, _game_state: &GameState, round_state: &RoundState, active: usize) -> PokerMove {
         let legal_actions: HashSet<PokerMove> = round_state.legal_actions();
         let street = round_state.street;
-        let my_cards = &round_state.hands[active];
-        let board_cards = &round_state.deck[..street as usize];
+        let _my_cards = &round_state.hands[active];
+        let _board_cards = &round_state.deck[..street as usize];
         let my_pip = round_state.pips[active];
         let opp_pip = round_state.pips[1 - active];
-        let my_stack = round_state.stacks[active];
-        let opp_stack = round_state.stacks[1 - active];
-        let continue_cost = opp_pip - my_pip;
-        let my_bounty = &round_state.bounties[active];
+        let _my_stack = round_state.stacks[active];
+        let _opp_stack = round_state.stacks[1 - active];
+        let _continue_cost = opp_pip - my_pip;
+        let _my_bounty = &round_state.bounties[active];
         
         let mut rng = rand::thread_rng();
         
         // Strategy implementation
         if legal_actions.contains(&PokerMove::Raise(0)) {
-            let (min_raise, max_raise) = round_state.raise_bounds();
+            let (min_raise, _max_raise) = round_state.raise_bounds();
             if rng.gen::<f64>() < 0.4 {
                 return PokerMove::Raise(min_raise);
             }